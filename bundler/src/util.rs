@@ -113,6 +113,40 @@ where
     pub fn insert(&self, v: V) -> bool {
         self.inner.insert(v, ()).is_none()
     }
+
+    /// Parallel iterator over the values currently stored in the set.
+    pub fn par_iter(&self) -> impl ParallelIterator<Item = V> + '_
+    where
+        V: Clone + Send,
+    {
+        self.inner.par_iter().map(|(v, ())| v)
+    }
+
+    /// Removes and returns every value in the set, in parallel when possible.
+    pub fn par_drain(&self) -> impl ParallelIterator<Item = V> + '_
+    where
+        V: Clone + Send + Sync,
+    {
+        self.inner.par_drain().map(|(v, ())| v)
+    }
+
+    /// Inserts every value from `iter`, in parallel when possible.
+    pub fn par_extend(&self, iter: impl IntoParallelIterator<Item = V>)
+    where
+        V: Send + Sync,
+    {
+        self.inner.par_extend(iter.into_par_iter().map(|v| (v, ())))
+    }
+
+    /// Builds a [`CHashSet`] from a parallel iterator of values.
+    pub fn from_par_iter(iter: impl IntoParallelIterator<Item = V>) -> Self
+    where
+        V: Send + Sync,
+    {
+        Self {
+            inner: CloneMap::from_par_iter(iter.into_par_iter().map(|v| (v, ()))),
+        }
+    }
 }
 
 impl<V> Default for CHashSet<V>
@@ -182,6 +216,146 @@ where
     pub fn insert(&self, k: K, v: V) -> Option<V> {
         self.inner.borrow_mut().insert(k, v)
     }
+
+    /// Returns the value for `k`, computing and storing it with `f` if it's
+    /// not already present.
+    ///
+    /// Unlike `get` followed by `insert`, this holds the lock for the shard
+    /// (or the `RefCell` borrow) across the miss, so `f` is guaranteed to run
+    /// at most once per key even when called concurrently.
+    #[cfg(feature = "concurrent")]
+    pub fn get_or_insert_with(&self, k: K, f: impl FnOnce() -> V) -> V {
+        self.inner.entry(k).or_insert_with(f).clone()
+    }
+
+    #[cfg(not(feature = "concurrent"))]
+    pub fn get_or_insert_with(&self, k: K, f: impl FnOnce() -> V) -> V {
+        self.inner.borrow_mut().entry(k).or_insert_with(f).clone()
+    }
+
+    /// Parallel iterator over `(key, value)` pairs.
+    ///
+    /// Either way the map is cloned into a `Vec` up front (from the dashmap
+    /// shards under `concurrent`, from the `RefCell` otherwise) and handed
+    /// to the crate-local `IntoParallelIterator` shim, which only runs in
+    /// parallel when this crate's own `rayon` feature is enabled. This keeps
+    /// `par_iter` from depending on dashmap's own rayon support, which would
+    /// otherwise require `concurrent` to imply `rayon`.
+    #[cfg(feature = "concurrent")]
+    pub fn par_iter(&self) -> impl ParallelIterator<Item = (K, V)>
+    where
+        K: Clone + Send,
+        V: Send,
+    {
+        let items: Vec<(K, V)> = self
+            .inner
+            .iter()
+            .map(|e| (e.key().clone(), e.value().clone()))
+            .collect();
+        items.into_par_iter()
+    }
+
+    #[cfg(not(feature = "concurrent"))]
+    pub fn par_iter(&self) -> impl ParallelIterator<Item = (K, V)>
+    where
+        K: Clone + Send,
+        V: Send,
+    {
+        let items: Vec<(K, V)> = self
+            .inner
+            .borrow()
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        items.into_par_iter()
+    }
+
+    /// Parallel iterator over the values, discarding the keys.
+    #[cfg(feature = "concurrent")]
+    pub fn par_values(&self) -> impl ParallelIterator<Item = V>
+    where
+        V: Send,
+    {
+        let items: Vec<V> = self.inner.iter().map(|e| e.value().clone()).collect();
+        items.into_par_iter()
+    }
+
+    #[cfg(not(feature = "concurrent"))]
+    pub fn par_values(&self) -> impl ParallelIterator<Item = V>
+    where
+        V: Send,
+    {
+        let items: Vec<V> = self.inner.borrow().values().cloned().collect();
+        items.into_par_iter()
+    }
+
+    /// Removes every entry from the map and returns a parallel iterator over
+    /// the removed `(key, value)` pairs.
+    ///
+    /// Under `concurrent`, the keys are snapshotted up front and each entry
+    /// is removed only when the iterator reaches it, so entries the iterator
+    /// does not consume stay in the map.
+    #[cfg(feature = "concurrent")]
+    pub fn par_drain(&self) -> impl ParallelIterator<Item = (K, V)> + '_
+    where
+        K: Clone + Send + Sync,
+        V: Send + Sync,
+    {
+        let keys: Vec<K> = self.inner.iter().map(|e| e.key().clone()).collect();
+        keys.into_par_iter().filter_map(move |k| self.inner.remove(&k))
+    }
+
+    #[cfg(not(feature = "concurrent"))]
+    pub fn par_drain(&self) -> impl ParallelIterator<Item = (K, V)>
+    where
+        K: Send,
+        V: Send,
+    {
+        let items: Vec<(K, V)> = self.inner.borrow_mut().drain().collect();
+        items.into_par_iter()
+    }
+
+    /// Inserts every `(key, value)` pair from `iter`.
+    ///
+    /// Under `concurrent` this feeds items directly into the shared dashmap
+    /// from however many threads rayon splits `iter` across. Otherwise `iter`
+    /// is first collected into a `Vec` and the pairs are then inserted one at
+    /// a time into the `RefCell`.
+    #[cfg(feature = "concurrent")]
+    pub fn par_extend(&self, iter: impl IntoParallelIterator<Item = (K, V)>)
+    where
+        K: Send + Sync,
+        V: Send + Sync,
+    {
+        iter.into_par_iter().for_each(|(k, v)| {
+            self.inner.insert(k, v);
+        });
+    }
+
+    #[cfg(not(feature = "concurrent"))]
+    pub fn par_extend(&self, iter: impl IntoParallelIterator<Item = (K, V)>)
+    where
+        K: Send,
+        V: Send,
+    {
+        let items: Vec<(K, V)> = iter.into_par_iter().collect();
+        let mut inner = self.inner.borrow_mut();
+        for (k, v) in items {
+            inner.insert(k, v);
+        }
+    }
+
+    /// Builds a [`CloneMap`] from a parallel iterator of `(key, value)`
+    /// pairs, following hashbrown's `FromParallelIterator`.
+    pub fn from_par_iter(iter: impl IntoParallelIterator<Item = (K, V)>) -> Self
+    where
+        K: Send + Sync,
+        V: Send + Sync,
+    {
+        let map = Self::default();
+        map.par_extend(iter);
+        map
+    }
 }
 
 pub(crate) struct HygieneRemover;
@@ -206,6 +380,63 @@ where
     (oper_a(), oper_b())
 }
 
+/// Runs `tasks` to completion and returns their results in the original
+/// order.
+///
+/// With `rayon`, the tasks are split in half and each half is dispatched via
+/// [`join`] recursively, so a balanced tree of two-way `join`s fans out over
+/// the thread pool instead of every caller having to build that tree by
+/// hand. Without `rayon`, the tasks just run in order.
+#[cfg(feature = "rayon")]
+pub(crate) fn join_all<R: Send>(mut tasks: Vec<Box<dyn FnOnce() -> R + Send>>) -> Vec<R> {
+    if tasks.len() <= 1 {
+        return tasks.into_iter().map(|task| task()).collect();
+    }
+
+    let right = tasks.split_off(tasks.len() / 2);
+    let left = tasks;
+
+    let (mut left, right) = join(|| join_all(left), || join_all(right));
+    left.extend(right);
+    left
+}
+
+#[cfg(not(feature = "rayon"))]
+pub(crate) fn join_all<R>(tasks: Vec<Box<dyn FnOnce() -> R + Send>>) -> Vec<R> {
+    tasks.into_iter().map(|task| task()).collect()
+}
+
+/// Lightweight `rayon::scope`-style helper for running a `Vec` of
+/// heterogeneous tasks side by side and collecting their results.
+///
+/// With `rayon`, every task is spawned into the same scope so they run
+/// concurrently; without it, they run sequentially. Either way the returned
+/// `Vec<R>` lines up with the input order.
+#[cfg(feature = "rayon")]
+pub(crate) fn scope<R, F>(tasks: Vec<F>) -> Vec<R>
+where
+    F: FnOnce() -> R + Send,
+    R: Send,
+{
+    let mut results: Vec<Option<R>> = tasks.iter().map(|_| None).collect();
+
+    rayon::scope(|s| {
+        for (slot, task) in results.iter_mut().zip(tasks) {
+            s.spawn(move |_| *slot = Some(task()));
+        }
+    });
+
+    results.into_iter().map(|r| r.expect("task did not run")).collect()
+}
+
+#[cfg(not(feature = "rayon"))]
+pub(crate) fn scope<R, F>(tasks: Vec<F>) -> Vec<R>
+where
+    F: FnOnce() -> R,
+{
+    tasks.into_iter().map(|task| task()).collect()
+}
+
 #[cfg(feature = "rayon")]
 pub(crate) use rayon::iter::IntoParallelIterator;
 
@@ -219,3 +450,110 @@ pub(crate) trait IntoParallelIterator: Sized + IntoIterator {
 
 #[cfg(not(feature = "rayon"))]
 impl<T> IntoParallelIterator for T where T: IntoIterator {}
+
+#[cfg(feature = "rayon")]
+pub(crate) use rayon::iter::ParallelIterator;
+
+/// Fake trait, implemented for every [`Iterator`] so `CloneMap`/`CHashSet`
+/// can expose the same `par_*` methods whether or not `rayon` is enabled.
+#[cfg(not(feature = "rayon"))]
+pub(crate) trait ParallelIterator: Iterator {}
+
+#[cfg(not(feature = "rayon"))]
+impl<T> ParallelIterator for T where T: Iterator {}
+
+/// Maps `v` with `op`, in parallel when `rayon` is enabled, while preserving
+/// the original order of the elements.
+///
+/// Each worker folds the slice it was handed into a local `Vec<R>`; workers
+/// are then combined by appending the right half's `Vec` onto the left
+/// half's inside a `LinkedList<Vec<R>>`, so relative order survives
+/// regardless of how rayon splits the work. The list is flattened into a
+/// single `Vec<R>` (pre-sized to the input length) at the end. Without
+/// `rayon`, this degrades to a plain sequential `map`.
+#[cfg(feature = "rayon")]
+pub(crate) fn par_map_vec<T, R, F>(v: Vec<T>, op: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Send + Sync,
+{
+    use std::collections::LinkedList;
+
+    let len = v.len();
+
+    let list = v
+        .into_par_iter()
+        .fold(
+            LinkedList::new,
+            |mut list: LinkedList<Vec<R>>, t| {
+                let r = op(t);
+                match list.back_mut() {
+                    Some(chunk) => chunk.push(r),
+                    None => list.push_back(vec![r]),
+                }
+                list
+            },
+        )
+        .reduce(LinkedList::new, |mut left, mut right| {
+            left.append(&mut right);
+            left
+        });
+
+    let mut out = Vec::with_capacity(len);
+    for mut chunk in list {
+        out.append(&mut chunk);
+    }
+    out
+}
+
+#[cfg(not(feature = "rayon"))]
+pub(crate) fn par_map_vec<T, R, F>(v: Vec<T>, op: F) -> Vec<R>
+where
+    F: Fn(T) -> R,
+{
+    v.into_iter().map(op).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn par_map_vec_preserves_order() {
+        let input: Vec<i32> = (0..1_000).collect();
+
+        let out = par_map_vec(input.clone(), |n| n * 2);
+
+        let expected: Vec<i32> = input.iter().map(|n| n * 2).collect();
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn clone_map_par_extend_and_from_par_iter() {
+        let pairs: Vec<(i32, i32)> = (0..100).map(|n| (n, n * 2)).collect();
+
+        let map = CloneMap::default();
+        map.par_extend(pairs.clone());
+        for (k, v) in &pairs {
+            assert_eq!(map.get(k), Some(*v));
+        }
+
+        let built = CloneMap::from_par_iter(pairs.clone());
+        for (k, v) in &pairs {
+            assert_eq!(built.get(k), Some(*v));
+        }
+    }
+
+    #[test]
+    fn chash_set_par_extend_and_from_par_iter() {
+        let values: Vec<i32> = (0..100).collect();
+
+        let set = CHashSet::default();
+        set.par_extend(values.clone());
+        assert!(values.iter().all(|v| !set.insert(*v)));
+
+        let built = CHashSet::from_par_iter(values.clone());
+        assert!(values.iter().all(|v| !built.insert(*v)));
+    }
+}